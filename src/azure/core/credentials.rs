@@ -0,0 +1,21 @@
+use azure::core::errors::AzureError;
+use chrono::{DateTime, Utc};
+use futures::future::Future;
+
+/// A token obtained from a `TokenCredential`, together with the instant it stops being valid.
+///
+/// Storage clients configured with a credential cache the most recently obtained token here and
+/// only call back into the credential once the token is close to `expires_on`.
+#[derive(Debug, Clone)]
+pub struct AccessToken {
+    pub token: String,
+    pub expires_on: DateTime<Utc>,
+}
+
+/// Anything able to produce an Azure AD bearer token for a set of scopes.
+///
+/// Accepted by `Client::from_credential` as an alternative to shared-key authentication. No
+/// concrete credential type is provided here; callers supply their own implementation.
+pub trait TokenCredential: Send + Sync {
+    fn get_token(&self, scopes: &[&str]) -> Box<dyn Future<Item = AccessToken, Error = AzureError> + Send>;
+}
@@ -0,0 +1,106 @@
+use http::request::Builder;
+
+/// These are the request-side counterparts of a blob's stored `Content-Type`, `Content-Encoding`,
+/// `Content-Language`, `Content-MD5`, `Cache-Control` and `Content-Disposition` properties. Unlike
+/// the generic HTTP `Content-*`/`Cache-Control` headers (which describe the *current* request or
+/// response body), Blob storage only accepts updates to these stored properties through the
+/// `x-ms-blob-content-*`/`x-ms-blob-cache-control` request headers, used by both Put Blob and Set
+/// Blob Properties. Do not use `azure::core`'s generic `ContentTypeOption`/`ContentEncodingOption`/
+/// `ContentMD5Option` here: those emit the plain `Content-Type`/`Content-Encoding`/`Content-MD5`
+/// headers, which Set Blob Properties (a bodyless request) silently ignores.
+pub(crate) const BLOB_CONTENT_TYPE: &str = "x-ms-blob-content-type";
+pub(crate) const BLOB_CONTENT_ENCODING: &str = "x-ms-blob-content-encoding";
+pub(crate) const BLOB_CONTENT_LANGUAGE: &str = "x-ms-blob-content-language";
+pub(crate) const BLOB_CONTENT_MD5: &str = "x-ms-blob-content-md5";
+pub(crate) const BLOB_CACHE_CONTROL: &str = "x-ms-blob-cache-control";
+pub(crate) const BLOB_CONTENT_DISPOSITION: &str = "x-ms-blob-content-disposition";
+
+pub trait ContentTypeOption<'a> {
+    fn content_type(&self) -> Option<&'a str>;
+
+    fn add_header(&self, builder: &mut Builder) {
+        if let Some(content_type) = self.content_type() {
+            builder.header(BLOB_CONTENT_TYPE, content_type);
+        }
+    }
+}
+
+pub trait ContentTypeSupport<'a> {
+    type O;
+    fn with_content_type(self, content_type: &'a str) -> Self::O;
+}
+
+pub trait ContentEncodingOption<'a> {
+    fn content_encoding(&self) -> Option<&'a str>;
+
+    fn add_header(&self, builder: &mut Builder) {
+        if let Some(content_encoding) = self.content_encoding() {
+            builder.header(BLOB_CONTENT_ENCODING, content_encoding);
+        }
+    }
+}
+
+pub trait ContentEncodingSupport<'a> {
+    type O;
+    fn with_content_encoding(self, content_encoding: &'a str) -> Self::O;
+}
+
+pub trait ContentMD5Option<'a> {
+    fn content_md5(&self) -> Option<&'a str>;
+
+    fn add_header(&self, builder: &mut Builder) {
+        if let Some(content_md5) = self.content_md5() {
+            builder.header(BLOB_CONTENT_MD5, content_md5);
+        }
+    }
+}
+
+pub trait ContentMD5Support<'a> {
+    type O;
+    fn with_content_md5(self, content_md5: &'a str) -> Self::O;
+}
+
+pub trait ContentLanguageOption<'a> {
+    fn content_language(&self) -> Option<&'a str>;
+
+    fn add_header(&self, builder: &mut Builder) {
+        if let Some(content_language) = self.content_language() {
+            builder.header(BLOB_CONTENT_LANGUAGE, content_language);
+        }
+    }
+}
+
+pub trait ContentLanguageSupport<'a> {
+    type O;
+    fn with_content_language(self, content_language: &'a str) -> Self::O;
+}
+
+pub trait CacheControlOption<'a> {
+    fn cache_control(&self) -> Option<&'a str>;
+
+    fn add_header(&self, builder: &mut Builder) {
+        if let Some(cache_control) = self.cache_control() {
+            builder.header(BLOB_CACHE_CONTROL, cache_control);
+        }
+    }
+}
+
+pub trait CacheControlSupport<'a> {
+    type O;
+    fn with_cache_control(self, cache_control: &'a str) -> Self::O;
+}
+
+pub trait ContentDispositionOption<'a> {
+    fn content_disposition(&self) -> Option<&'a str>;
+
+    fn add_header(&self, builder: &mut Builder) {
+        if let Some(content_disposition) = self.content_disposition() {
+            builder.header(BLOB_CONTENT_DISPOSITION, content_disposition);
+        }
+    }
+}
+
+pub trait ContentDispositionSupport<'a> {
+    type O;
+    fn with_content_disposition(self, content_disposition: &'a str) -> Self::O;
+}
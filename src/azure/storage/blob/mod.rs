@@ -0,0 +1,48 @@
+pub(crate) mod blob_content_headers;
+pub mod requests;
+pub mod responses;
+
+use azure::core::sas::{SasPermissions, SasResource, SharedAccessSignature};
+use azure::core::{BlobNameRequired, ClientRequired, ContainerNameRequired, COMPLETE_ENCODE_SET};
+use chrono::{DateTime, Utc};
+use url::percent_encoding::utf8_percent_encode;
+
+#[inline]
+pub(crate) fn generate_blob_uri<'a, T>(t: &T, params: Option<&str>) -> String
+where
+    T: ClientRequired<'a> + ContainerNameRequired<'a> + BlobNameRequired<'a>,
+{
+    match params {
+        Some(ref params) => format!(
+            "https://{}.blob.core.windows.net/{}/{}?{}",
+            t.client().account(),
+            utf8_percent_encode(t.container_name(), COMPLETE_ENCODE_SET),
+            utf8_percent_encode(t.blob_name(), COMPLETE_ENCODE_SET),
+            params
+        ),
+        None => format!(
+            "https://{}.blob.core.windows.net/{}/{}",
+            t.client().account(),
+            utf8_percent_encode(t.container_name(), COMPLETE_ENCODE_SET),
+            utf8_percent_encode(t.blob_name(), COMPLETE_ENCODE_SET),
+        ),
+    }
+}
+
+/// Mints a time-limited, pre-signed blob SAS URI so callers can hand out read/write access without
+/// sharing the account key, analogous to an S3 presigned URL. Signed with the `Client`'s own
+/// account key; panics if `t.client()` was built with `Client::from_credential`, which has no
+/// account key to sign with.
+#[inline]
+pub fn generate_sas_uri<'a, T>(t: &T, permissions: SasPermissions, expiry: DateTime<Utc>) -> String
+where
+    T: ClientRequired<'a> + ContainerNameRequired<'a> + BlobNameRequired<'a>,
+{
+    let key = t
+        .client()
+        .shared_key()
+        .expect("generate_sas_uri requires a Client constructed with an account key, not from_credential");
+    let sas = SharedAccessSignature::new(t.client().account(), t.container_name(), SasResource::Blob, permissions, expiry)
+        .with_blob_name(t.blob_name());
+    generate_blob_uri(t, Some(&sas.token(key)))
+}
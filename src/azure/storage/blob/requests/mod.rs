@@ -0,0 +1,17 @@
+mod acquire_blob_lease_builder;
+mod break_blob_lease_builder;
+mod change_blob_lease_builder;
+mod get_blob_properties_builder;
+mod put_blob_builder;
+mod release_blob_lease_builder;
+mod renew_blob_lease_builder;
+mod set_blob_properties_builder;
+
+pub use self::acquire_blob_lease_builder::AcquireBlobLeaseBuilder;
+pub use self::break_blob_lease_builder::BreakBlobLeaseBuilder;
+pub use self::change_blob_lease_builder::ChangeBlobLeaseBuilder;
+pub use self::get_blob_properties_builder::GetBlobPropertiesBuilder;
+pub use self::put_blob_builder::PutBlobBuilder;
+pub use self::release_blob_lease_builder::ReleaseBlobLeaseBuilder;
+pub use self::renew_blob_lease_builder::RenewBlobLeaseBuilder;
+pub use self::set_blob_properties_builder::SetBlobPropertiesBuilder;
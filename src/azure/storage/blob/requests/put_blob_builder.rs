@@ -0,0 +1,474 @@
+use azure::core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure::core::{
+    BlobNameRequired, BlobNameSupport, BodyRequired, BodySupport, ClientRequestIdOption, ClientRequestIdSupport, ClientRequired,
+    ContainerNameRequired, ContainerNameSupport, TimeoutOption, TimeoutSupport,
+};
+use azure::core::{No, ToAssign, Yes};
+use azure::storage::blob::blob_content_headers::{
+    CacheControlOption, CacheControlSupport, ContentDispositionOption, ContentDispositionSupport, ContentEncodingOption,
+    ContentEncodingSupport, ContentLanguageOption, ContentLanguageSupport, ContentMD5Option, ContentMD5Support, ContentTypeOption,
+    ContentTypeSupport,
+};
+use azure::storage::blob::generate_blob_uri;
+use azure::storage::blob::responses::PutBlobResponse;
+use azure::storage::client::Client;
+use futures::future::{done, Future};
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+const BLOB_TYPE: &str = "x-ms-blob-type";
+
+#[derive(Debug, Clone)]
+pub struct PutBlobBuilder<'a, ContainerNameSet, BlobNameSet, BodySet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    BodySet: ToAssign,
+{
+    client: &'a Client,
+    p_container_name: PhantomData<ContainerNameSet>,
+    p_blob_name: PhantomData<BlobNameSet>,
+    p_body: PhantomData<BodySet>,
+    container_name: Option<&'a str>,
+    blob_name: Option<&'a str>,
+    body: Option<&'a [u8]>,
+    content_type: Option<&'a str>,
+    content_encoding: Option<&'a str>,
+    content_language: Option<&'a str>,
+    content_md5: Option<&'a str>,
+    cache_control: Option<&'a str>,
+    content_disposition: Option<&'a str>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> PutBlobBuilder<'a, No, No, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> PutBlobBuilder<'a, No, No, No> {
+        PutBlobBuilder {
+            client,
+            p_container_name: PhantomData {},
+            container_name: None,
+            p_blob_name: PhantomData {},
+            blob_name: None,
+            p_body: PhantomData {},
+            body: None,
+            content_type: None,
+            content_encoding: None,
+            content_language: None,
+            content_md5: None,
+            cache_control: None,
+            content_disposition: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, BodySet> ClientRequired<'a> for PutBlobBuilder<'a, ContainerNameSet, BlobNameSet, BodySet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    BodySet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a, BlobNameSet, BodySet> ContainerNameRequired<'a> for PutBlobBuilder<'a, Yes, BlobNameSet, BodySet>
+where
+    BlobNameSet: ToAssign,
+    BodySet: ToAssign,
+{
+    #[inline]
+    fn container_name(&self) -> &'a str {
+        self.container_name.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet, BodySet> BlobNameRequired<'a> for PutBlobBuilder<'a, ContainerNameSet, Yes, BodySet>
+where
+    ContainerNameSet: ToAssign,
+    BodySet: ToAssign,
+{
+    #[inline]
+    fn blob_name(&self) -> &'a str {
+        self.blob_name.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet> BodyRequired<'a> for PutBlobBuilder<'a, ContainerNameSet, BlobNameSet, Yes>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+{
+    #[inline]
+    fn body(&self) -> &'a [u8] {
+        self.body.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, BodySet> ContentTypeOption<'a> for PutBlobBuilder<'a, ContainerNameSet, BlobNameSet, BodySet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    BodySet: ToAssign,
+{
+    #[inline]
+    fn content_type(&self) -> Option<&'a str> {
+        self.content_type
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, BodySet> ContentEncodingOption<'a> for PutBlobBuilder<'a, ContainerNameSet, BlobNameSet, BodySet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    BodySet: ToAssign,
+{
+    #[inline]
+    fn content_encoding(&self) -> Option<&'a str> {
+        self.content_encoding
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, BodySet> ContentLanguageOption<'a> for PutBlobBuilder<'a, ContainerNameSet, BlobNameSet, BodySet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    BodySet: ToAssign,
+{
+    #[inline]
+    fn content_language(&self) -> Option<&'a str> {
+        self.content_language
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, BodySet> ContentMD5Option<'a> for PutBlobBuilder<'a, ContainerNameSet, BlobNameSet, BodySet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    BodySet: ToAssign,
+{
+    #[inline]
+    fn content_md5(&self) -> Option<&'a str> {
+        self.content_md5
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, BodySet> CacheControlOption<'a> for PutBlobBuilder<'a, ContainerNameSet, BlobNameSet, BodySet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    BodySet: ToAssign,
+{
+    #[inline]
+    fn cache_control(&self) -> Option<&'a str> {
+        self.cache_control
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, BodySet> ContentDispositionOption<'a> for PutBlobBuilder<'a, ContainerNameSet, BlobNameSet, BodySet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    BodySet: ToAssign,
+{
+    #[inline]
+    fn content_disposition(&self) -> Option<&'a str> {
+        self.content_disposition
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, BodySet> TimeoutOption for PutBlobBuilder<'a, ContainerNameSet, BlobNameSet, BodySet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    BodySet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, BodySet> ClientRequestIdOption<'a> for PutBlobBuilder<'a, ContainerNameSet, BlobNameSet, BodySet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    BodySet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, BodySet> ContainerNameSupport<'a> for PutBlobBuilder<'a, ContainerNameSet, BlobNameSet, BodySet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    BodySet: ToAssign,
+{
+    type O = PutBlobBuilder<'a, Yes, BlobNameSet, BodySet>;
+
+    #[inline]
+    fn with_container_name(self, container_name: &'a str) -> Self::O {
+        PutBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_body: PhantomData {},
+            container_name: Some(container_name),
+            blob_name: self.blob_name,
+            body: self.body,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            content_md5: self.content_md5,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, BodySet> BlobNameSupport<'a> for PutBlobBuilder<'a, ContainerNameSet, BlobNameSet, BodySet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    BodySet: ToAssign,
+{
+    type O = PutBlobBuilder<'a, ContainerNameSet, Yes, BodySet>;
+
+    #[inline]
+    fn with_blob_name(self, blob_name: &'a str) -> Self::O {
+        PutBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_body: PhantomData {},
+            container_name: self.container_name,
+            blob_name: Some(blob_name),
+            body: self.body,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            content_md5: self.content_md5,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, BodySet> BodySupport<'a> for PutBlobBuilder<'a, ContainerNameSet, BlobNameSet, BodySet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    BodySet: ToAssign,
+{
+    type O = PutBlobBuilder<'a, ContainerNameSet, BlobNameSet, Yes>;
+
+    #[inline]
+    fn with_body(self, body: &'a [u8]) -> Self::O {
+        PutBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_body: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            body: Some(body),
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            content_md5: self.content_md5,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, BodySet> ContentTypeSupport<'a> for PutBlobBuilder<'a, ContainerNameSet, BlobNameSet, BodySet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    BodySet: ToAssign,
+{
+    type O = PutBlobBuilder<'a, ContainerNameSet, BlobNameSet, BodySet>;
+
+    #[inline]
+    fn with_content_type(self, content_type: &'a str) -> Self::O {
+        PutBlobBuilder {
+            content_type: Some(content_type),
+            ..self
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, BodySet> ContentEncodingSupport<'a> for PutBlobBuilder<'a, ContainerNameSet, BlobNameSet, BodySet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    BodySet: ToAssign,
+{
+    type O = PutBlobBuilder<'a, ContainerNameSet, BlobNameSet, BodySet>;
+
+    #[inline]
+    fn with_content_encoding(self, content_encoding: &'a str) -> Self::O {
+        PutBlobBuilder {
+            content_encoding: Some(content_encoding),
+            ..self
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, BodySet> ContentLanguageSupport<'a> for PutBlobBuilder<'a, ContainerNameSet, BlobNameSet, BodySet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    BodySet: ToAssign,
+{
+    type O = PutBlobBuilder<'a, ContainerNameSet, BlobNameSet, BodySet>;
+
+    #[inline]
+    fn with_content_language(self, content_language: &'a str) -> Self::O {
+        PutBlobBuilder {
+            content_language: Some(content_language),
+            ..self
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, BodySet> ContentMD5Support<'a> for PutBlobBuilder<'a, ContainerNameSet, BlobNameSet, BodySet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    BodySet: ToAssign,
+{
+    type O = PutBlobBuilder<'a, ContainerNameSet, BlobNameSet, BodySet>;
+
+    #[inline]
+    fn with_content_md5(self, content_md5: &'a str) -> Self::O {
+        PutBlobBuilder {
+            content_md5: Some(content_md5),
+            ..self
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, BodySet> CacheControlSupport<'a> for PutBlobBuilder<'a, ContainerNameSet, BlobNameSet, BodySet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    BodySet: ToAssign,
+{
+    type O = PutBlobBuilder<'a, ContainerNameSet, BlobNameSet, BodySet>;
+
+    #[inline]
+    fn with_cache_control(self, cache_control: &'a str) -> Self::O {
+        PutBlobBuilder {
+            cache_control: Some(cache_control),
+            ..self
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, BodySet> ContentDispositionSupport<'a> for PutBlobBuilder<'a, ContainerNameSet, BlobNameSet, BodySet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    BodySet: ToAssign,
+{
+    type O = PutBlobBuilder<'a, ContainerNameSet, BlobNameSet, BodySet>;
+
+    #[inline]
+    fn with_content_disposition(self, content_disposition: &'a str) -> Self::O {
+        PutBlobBuilder {
+            content_disposition: Some(content_disposition),
+            ..self
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, BodySet> TimeoutSupport for PutBlobBuilder<'a, ContainerNameSet, BlobNameSet, BodySet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    BodySet: ToAssign,
+{
+    type O = PutBlobBuilder<'a, ContainerNameSet, BlobNameSet, BodySet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        PutBlobBuilder {
+            timeout: Some(timeout),
+            ..self
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, BlobNameSet, BodySet> ClientRequestIdSupport<'a> for PutBlobBuilder<'a, ContainerNameSet, BlobNameSet, BodySet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    BodySet: ToAssign,
+{
+    type O = PutBlobBuilder<'a, ContainerNameSet, BlobNameSet, BodySet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        PutBlobBuilder {
+            client_request_id: Some(client_request_id),
+            ..self
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, ContainerNameSet, BlobNameSet, BodySet> PutBlobBuilder<'a, ContainerNameSet, BlobNameSet, BodySet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    BodySet: ToAssign,
+{}
+
+impl<'a> PutBlobBuilder<'a, Yes, Yes, Yes> {
+    pub fn finalize(self) -> impl Future<Item = PutBlobResponse, Error = AzureError> {
+        let mut uri = generate_blob_uri(&self, None);
+
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}?{}", uri, nm);
+        }
+
+        let body = self.body;
+
+        let req = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            |ref mut request| {
+                request.header(BLOB_TYPE, "BlockBlob");
+                ContentTypeOption::add_header(&self, request);
+                ContentEncodingOption::add_header(&self, request);
+                ContentLanguageOption::add_header(&self, request);
+                ContentMD5Option::add_header(&self, request);
+                CacheControlOption::add_header(&self, request);
+                ContentDispositionOption::add_header(&self, request);
+                ClientRequestIdOption::add_header(&self, request);
+            },
+            Some(body),
+        );
+
+        done(req)
+            .from_err()
+            .and_then(move |future_response| check_status_extract_headers_and_body(future_response, StatusCode::CREATED))
+            .and_then(|(headers, _body)| done(PutBlobResponse::from_headers(&headers)))
+    }
+}
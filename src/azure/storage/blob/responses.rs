@@ -0,0 +1,199 @@
+use azure::core::errors::AzureError;
+use azure::core::headers::{LEASE_ID, LEASE_TIME};
+use azure::core::lease::LeaseId;
+use chrono::{DateTime, Utc};
+use http::{header, HeaderMap};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReleaseBlobLeaseResponse {}
+
+impl ReleaseBlobLeaseResponse {
+    pub(crate) fn from_headers(_headers: &HeaderMap) -> Result<ReleaseBlobLeaseResponse, AzureError> {
+        Ok(ReleaseBlobLeaseResponse {})
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AcquireBlobLeaseResponse {
+    pub lease_id: LeaseId,
+}
+
+impl AcquireBlobLeaseResponse {
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Result<AcquireBlobLeaseResponse, AzureError> {
+        let lease_id = match headers.get(LEASE_ID) {
+            Some(lease_id) => LeaseId::from_str(lease_id.to_str()?)?,
+            None => return Err(AzureError::MissingHeaderError(LEASE_ID.to_owned())),
+        };
+
+        Ok(AcquireBlobLeaseResponse { lease_id })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RenewBlobLeaseResponse {
+    pub lease_id: LeaseId,
+}
+
+impl RenewBlobLeaseResponse {
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Result<RenewBlobLeaseResponse, AzureError> {
+        let lease_id = match headers.get(LEASE_ID) {
+            Some(lease_id) => LeaseId::from_str(lease_id.to_str()?)?,
+            None => return Err(AzureError::MissingHeaderError(LEASE_ID.to_owned())),
+        };
+
+        Ok(RenewBlobLeaseResponse { lease_id })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChangeBlobLeaseResponse {
+    pub lease_id: LeaseId,
+}
+
+impl ChangeBlobLeaseResponse {
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Result<ChangeBlobLeaseResponse, AzureError> {
+        let lease_id = match headers.get(LEASE_ID) {
+            Some(lease_id) => LeaseId::from_str(lease_id.to_str()?)?,
+            None => return Err(AzureError::MissingHeaderError(LEASE_ID.to_owned())),
+        };
+
+        Ok(ChangeBlobLeaseResponse { lease_id })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BreakBlobLeaseResponse {
+    pub lease_time: u64,
+}
+
+impl BreakBlobLeaseResponse {
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Result<BreakBlobLeaseResponse, AzureError> {
+        let lease_time = match headers.get(LEASE_TIME) {
+            Some(lease_time) => u64::from_str(lease_time.to_str()?)?,
+            None => return Err(AzureError::MissingHeaderError(LEASE_TIME.to_owned())),
+        };
+
+        Ok(BreakBlobLeaseResponse { lease_time })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SetBlobPropertiesResponse {
+    pub e_tag: String,
+    pub last_modified: DateTime<Utc>,
+}
+
+impl SetBlobPropertiesResponse {
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Result<SetBlobPropertiesResponse, AzureError> {
+        let e_tag = match headers.get(header::ETAG) {
+            Some(e_tag) => e_tag.to_str()?.to_owned(),
+            None => return Err(AzureError::MissingHeaderError(header::ETAG.as_str().to_owned())),
+        };
+
+        let last_modified = match headers.get(header::LAST_MODIFIED) {
+            Some(last_modified) => last_modified.to_str()?,
+            None => return Err(AzureError::MissingHeaderError(header::LAST_MODIFIED.as_str().to_owned())),
+        };
+        let last_modified = DateTime::parse_from_rfc2822(last_modified)?;
+        let last_modified = DateTime::from_utc(last_modified.naive_utc(), Utc);
+
+        Ok(SetBlobPropertiesResponse { e_tag, last_modified })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PutBlobResponse {
+    pub e_tag: String,
+    pub last_modified: DateTime<Utc>,
+    pub content_md5: Option<String>,
+}
+
+impl PutBlobResponse {
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Result<PutBlobResponse, AzureError> {
+        let e_tag = match headers.get(header::ETAG) {
+            Some(e_tag) => e_tag.to_str()?.to_owned(),
+            None => return Err(AzureError::MissingHeaderError(header::ETAG.as_str().to_owned())),
+        };
+
+        let last_modified = match headers.get(header::LAST_MODIFIED) {
+            Some(last_modified) => last_modified.to_str()?,
+            None => return Err(AzureError::MissingHeaderError(header::LAST_MODIFIED.as_str().to_owned())),
+        };
+        let last_modified = DateTime::parse_from_rfc2822(last_modified)?;
+        let last_modified = DateTime::from_utc(last_modified.naive_utc(), Utc);
+
+        let content_md5 = match headers.get(header::CONTENT_MD5) {
+            Some(content_md5) => Some(content_md5.to_str()?.to_owned()),
+            None => None,
+        };
+
+        Ok(PutBlobResponse {
+            e_tag,
+            last_modified,
+            content_md5,
+        })
+    }
+}
+
+/// The stored content properties Blob storage echoes back on Get/Head Blob responses as plain
+/// HTTP headers (as opposed to the `x-ms-blob-content-*` request headers Put Blob and Set Blob
+/// Properties use to update them).
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct BlobProperties {
+    pub content_type: Option<String>,
+    pub content_encoding: Option<String>,
+    pub content_language: Option<String>,
+    pub content_md5: Option<String>,
+    pub cache_control: Option<String>,
+    pub content_disposition: Option<String>,
+}
+
+impl BlobProperties {
+    fn from_headers(headers: &HeaderMap) -> Result<BlobProperties, AzureError> {
+        let header_str = |name: header::HeaderName| -> Result<Option<String>, AzureError> {
+            match headers.get(name) {
+                Some(value) => Ok(Some(value.to_str()?.to_owned())),
+                None => Ok(None),
+            }
+        };
+
+        Ok(BlobProperties {
+            content_type: header_str(header::CONTENT_TYPE)?,
+            content_encoding: header_str(header::CONTENT_ENCODING)?,
+            content_language: header_str(header::CONTENT_LANGUAGE)?,
+            content_md5: header_str(header::CONTENT_MD5)?,
+            cache_control: header_str(header::CACHE_CONTROL)?,
+            content_disposition: header_str(header::CONTENT_DISPOSITION)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GetBlobPropertiesResponse {
+    pub e_tag: String,
+    pub last_modified: DateTime<Utc>,
+    pub properties: BlobProperties,
+}
+
+impl GetBlobPropertiesResponse {
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Result<GetBlobPropertiesResponse, AzureError> {
+        let e_tag = match headers.get(header::ETAG) {
+            Some(e_tag) => e_tag.to_str()?.to_owned(),
+            None => return Err(AzureError::MissingHeaderError(header::ETAG.as_str().to_owned())),
+        };
+
+        let last_modified = match headers.get(header::LAST_MODIFIED) {
+            Some(last_modified) => last_modified.to_str()?,
+            None => return Err(AzureError::MissingHeaderError(header::LAST_MODIFIED.as_str().to_owned())),
+        };
+        let last_modified = DateTime::parse_from_rfc2822(last_modified)?;
+        let last_modified = DateTime::from_utc(last_modified.naive_utc(), Utc);
+
+        Ok(GetBlobPropertiesResponse {
+            e_tag,
+            last_modified,
+            properties: BlobProperties::from_headers(headers)?,
+        })
+    }
+}
@@ -0,0 +1,250 @@
+use azure::core::credentials::{AccessToken, TokenCredential};
+use azure::core::errors::AzureError;
+use base64;
+use chrono::Utc;
+use futures::future::{self, Future};
+use hmac::{Hmac, Mac};
+use http::request::Builder;
+use http::{HeaderMap, Response};
+use hyper::client::HttpConnector;
+use hyper::{Body, Client as HyperClient, Method};
+use hyper_tls::HttpsConnector;
+use sha2::Sha256;
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use url::Url;
+
+const AZURE_VERSION: &str = "2018-03-28";
+// Azure AD refreshes are only attempted once the cached token is within this window of expiring.
+// Concurrent requests that all find the token inside the window each fetch a new token; the
+// refreshes are not de-duplicated, and the last one to finish is the one left in the cache.
+const TOKEN_REFRESH_WINDOW_SECONDS: i64 = 120;
+
+enum Auth {
+    SharedKey {
+        account: String,
+        key: Vec<u8>,
+    },
+    Token {
+        scopes: Vec<String>,
+        credential: Arc<dyn TokenCredential>,
+        cached: Arc<Mutex<Option<AccessToken>>>,
+    },
+}
+
+pub struct Client {
+    account: String,
+    auth: Auth,
+    hyper_client: HyperClient<HttpsConnector<HttpConnector>, Body>,
+}
+
+impl Client {
+    pub fn new(account: &str, key: &str) -> Result<Client, AzureError> {
+        let key = base64::decode(key)?;
+        let https = HttpsConnector::new(4)?;
+
+        Ok(Client {
+            account: account.to_owned(),
+            auth: Auth::SharedKey {
+                account: account.to_owned(),
+                key,
+            },
+            hyper_client: HyperClient::builder().build(https),
+        })
+    }
+
+    /// Builds a client authenticated with an Azure AD bearer token instead of an account key, for
+    /// use with managed identities or service-principal credentials. `scopes` is typically
+    /// `&["https://storage.azure.com/.default"]` for Blob storage.
+    pub fn from_credential<C>(account: &str, credential: C, scopes: &[&str]) -> Result<Client, AzureError>
+    where
+        C: TokenCredential + 'static,
+    {
+        let https = HttpsConnector::new(4)?;
+
+        Ok(Client {
+            account: account.to_owned(),
+            auth: Auth::Token {
+                scopes: scopes.iter().map(|s| (*s).to_owned()).collect(),
+                credential: Arc::new(credential),
+                cached: Arc::new(Mutex::new(None)),
+            },
+            hyper_client: HyperClient::builder().build(https),
+        })
+    }
+
+    pub fn emulator(blob_storage_url: &Url, _table_storage_url: &Url) -> Result<Client, AzureError> {
+        // well-known devstorage account name and key, see
+        // https://docs.microsoft.com/en-us/azure/storage/common/storage-use-emulator
+        let account = "devstoreaccount1";
+        let key = "Eby8vdM02xNOcqFlqUwJPLlmEtlCDXJ1OUzFT50uSRZ6IFsuFq2UVErCz4I6tq/K1SZFPTOtr/KBHBeksoGMGw==";
+
+        let mut client = Client::new(account, key)?;
+        client.account = blob_storage_url.as_str().trim_end_matches('/').to_owned();
+        Ok(client)
+    }
+
+    #[inline]
+    pub fn account(&self) -> &str {
+        &self.account
+    }
+
+    /// The account's decoded shared key, used to sign service SAS tokens. `None` for a client
+    /// built with `from_credential`, which has no account key to sign with.
+    #[inline]
+    pub(crate) fn shared_key(&self) -> Option<&[u8]> {
+        match self.auth {
+            Auth::SharedKey { ref key, .. } => Some(key),
+            Auth::Token { .. } => None,
+        }
+    }
+
+    pub fn perform_request<F>(
+        &self,
+        uri: &str,
+        method: &Method,
+        headers_func: F,
+        request_body: Option<&[u8]>,
+    ) -> Result<Box<dyn Future<Item = Response<Body>, Error = AzureError> + Send>, AzureError>
+    where
+        F: FnOnce(&mut Builder),
+    {
+        let mut request = Builder::new();
+        request.method(method.clone());
+        request.uri(uri);
+        request.header("x-ms-version", AZURE_VERSION);
+        request.header("x-ms-date", Utc::now().format("%a, %d %h %Y %T GMT").to_string());
+        headers_func(&mut request);
+
+        let body = request_body.map(<[u8]>::to_vec).unwrap_or_default();
+        let content_length = body.len();
+
+        match self.auth {
+            Auth::SharedKey { ref account, ref key } => {
+                let headers = request
+                    .headers_mut()
+                    .expect("the request builder has no earlier error to report")
+                    .clone();
+
+                let signature = sign_request_shared_key(account, key, method, uri, &headers, content_length);
+                request.header("Authorization", format!("SharedKey {}:{}", account, signature));
+
+                let request = request.body(Body::from(body))?;
+                Ok(Box::new(self.hyper_client.request(request).from_err()))
+            }
+            Auth::Token {
+                ref scopes,
+                ref credential,
+                ref cached,
+            } => {
+                let hyper_client = self.hyper_client.clone();
+                let get_token = bearer_token(scopes, Arc::clone(credential), Arc::clone(cached));
+
+                let send_with_token = get_token.and_then(move |token| {
+                    let mut request = request;
+                    request.header("Authorization", format!("Bearer {}", token));
+                    let request = request.body(Body::from(body))?;
+                    Ok(hyper_client.request(request).from_err())
+                });
+
+                Ok(Box::new(send_with_token.flatten()))
+            }
+        }
+    }
+}
+
+/// Resolves to the cached bearer token if it is still fresh, otherwise fetches a new one through
+/// `credential` and refreshes the cache. This never blocks the calling thread: the token fetch is
+/// composed as a future and handed back to `perform_request`, which only attaches it to the
+/// eventual request once it resolves, instead of calling `.wait()` on it up front.
+fn bearer_token(
+    scopes: &[String],
+    credential: Arc<dyn TokenCredential>,
+    cached: Arc<Mutex<Option<AccessToken>>>,
+) -> Box<dyn Future<Item = String, Error = AzureError> + Send> {
+    let needs_refresh = {
+        let cached = cached.lock().expect("token cache mutex poisoned");
+        match *cached {
+            Some(ref token) => (token.expires_on - Utc::now()).num_seconds() < TOKEN_REFRESH_WINDOW_SECONDS,
+            None => true,
+        }
+    };
+
+    if !needs_refresh {
+        let cached = cached.lock().expect("token cache mutex poisoned");
+        return Box::new(future::ok(cached.as_ref().unwrap().token.clone()));
+    }
+
+    let scopes: Vec<&str> = scopes.iter().map(String::as_str).collect();
+
+    Box::new(credential.get_token(&scopes).and_then(move |token| {
+        let value = token.token.clone();
+        *cached.lock().expect("token cache mutex poisoned") = Some(token);
+        Ok(value)
+    }))
+}
+
+fn header_str<'h>(headers: &'h HeaderMap, name: &str) -> &'h str {
+    headers.get(name).and_then(|v| v.to_str().ok()).unwrap_or("")
+}
+
+fn canonicalized_headers(headers: &HeaderMap) -> String {
+    let mut ms_headers: Vec<(String, String)> = headers
+        .iter()
+        .filter(|(name, _)| name.as_str().starts_with("x-ms-"))
+        .map(|(name, value)| (name.as_str().to_owned(), value.to_str().unwrap_or("").to_owned()))
+        .collect();
+    ms_headers.sort_by(|a, b| a.0.cmp(&b.0));
+
+    ms_headers.into_iter().map(|(name, value)| format!("{}:{}\n", name, value)).collect()
+}
+
+fn canonicalized_resource(account: &str, uri: &str) -> String {
+    let parsed = Url::parse(uri).expect("perform_request is always called with an absolute URI");
+    let mut resource = format!("/{}{}", account, parsed.path());
+
+    let mut params: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for (name, value) in parsed.query_pairs() {
+        params.entry(name.to_lowercase()).or_insert_with(Vec::new).push(value.into_owned());
+    }
+
+    for (name, mut values) in params {
+        values.sort();
+        resource.push_str(&format!("\n{}:{}", name, values.join(",")));
+    }
+
+    resource
+}
+
+/// Builds the `StringToSign` for the `SharedKey` authorization scheme documented at
+/// https://docs.microsoft.com/en-us/rest/api/storageservices/authorize-with-shared-key, using
+/// `x-ms-date` (already set by `perform_request`) in place of a `Date` header.
+fn string_to_sign(account: &str, method: &Method, uri: &str, headers: &HeaderMap, content_length: usize) -> String {
+    let content_length = if content_length == 0 { String::new() } else { content_length.to_string() };
+
+    format!(
+        "{method}\n{content_encoding}\n{content_language}\n{content_length}\n{content_md5}\n{content_type}\n{date}\n{if_modified_since}\n{if_match}\n{if_none_match}\n{if_unmodified_since}\n{range}\n{canonicalized_headers}{canonicalized_resource}",
+        method = method.as_str(),
+        content_encoding = header_str(headers, "content-encoding"),
+        content_language = header_str(headers, "content-language"),
+        content_length = content_length,
+        content_md5 = header_str(headers, "content-md5"),
+        content_type = header_str(headers, "content-type"),
+        date = "",
+        if_modified_since = header_str(headers, "if-modified-since"),
+        if_match = header_str(headers, "if-match"),
+        if_none_match = header_str(headers, "if-none-match"),
+        if_unmodified_since = header_str(headers, "if-unmodified-since"),
+        range = header_str(headers, "range"),
+        canonicalized_headers = canonicalized_headers(headers),
+        canonicalized_resource = canonicalized_resource(account, uri),
+    )
+}
+
+fn sign_request_shared_key(account: &str, key: &[u8], method: &Method, uri: &str, headers: &HeaderMap, content_length: usize) -> String {
+    let string_to_sign = string_to_sign(account, method, uri, headers, content_length);
+
+    let mut mac = Hmac::<Sha256>::new_varkey(key).expect("HMAC accepts keys of any length");
+    mac.input(string_to_sign.as_bytes());
+    base64::encode(&mac.result().code())
+}
@@ -7,6 +7,7 @@ use azure::core::{
     headers::{BLOB_PUBLIC_ACCESS, HAS_IMMUTABILITY_POLICY, HAS_LEGAL_HOLD, LEASE_DURATION, LEASE_STATE, LEASE_STATUS, META_PREFIX},
     lease::{LeaseDuration, LeaseState, LeaseStatus},
     parsing::{cast_must, cast_optional, traverse, FromStringOptional},
+    sas::{SasPermissions, SasResource, SharedAccessSignature},
     ClientRequired, ContainerNameRequired, COMPLETE_ENCODE_SET,
 };
 use chrono::{DateTime, Utc};
@@ -241,3 +242,20 @@ where
         ),
     }
 }
+
+/// Mints a time-limited, pre-signed container SAS URI so callers can hand out read/write access
+/// without sharing the account key, analogous to an S3 presigned URL. Signed with the `Client`'s
+/// own account key; panics if `t.client()` was built with `Client::from_credential`, which has no
+/// account key to sign with.
+#[inline]
+pub fn generate_sas_uri<'a, T>(t: &T, permissions: SasPermissions, expiry: DateTime<Utc>) -> String
+where
+    T: ClientRequired<'a> + ContainerNameRequired<'a>,
+{
+    let key = t
+        .client()
+        .shared_key()
+        .expect("generate_sas_uri requires a Client constructed with an account key, not from_credential");
+    let sas = SharedAccessSignature::new(t.client().account(), t.container_name(), SasResource::Container, permissions, expiry);
+    generate_container_uri(t, Some(&sas.token(key)))
+}
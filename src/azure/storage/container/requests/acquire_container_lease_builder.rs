@@ -0,0 +1,255 @@
+use azure::core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure::core::headers::LEASE_ACTION;
+use azure::core::lease::LeaseId;
+use azure::core::{
+    ClientRequestIdOption, ClientRequestIdSupport, ClientRequired, ContainerNameRequired, ContainerNameSupport, LeaseDurationRequired,
+    LeaseDurationSupport, ProposedLeaseIdOption, ProposedLeaseIdSupport, TimeoutOption, TimeoutSupport,
+};
+use azure::core::{No, ToAssign, Yes};
+use azure::storage::container::generate_container_uri;
+use azure::storage::container::responses::AcquireContainerLeaseResponse;
+use azure::storage::client::Client;
+use futures::future::{done, Future};
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct AcquireContainerLeaseBuilder<'a, ContainerNameSet, LeaseDurationSet>
+where
+    ContainerNameSet: ToAssign,
+    LeaseDurationSet: ToAssign,
+{
+    client: &'a Client,
+    p_container_name: PhantomData<ContainerNameSet>,
+    p_lease_duration: PhantomData<LeaseDurationSet>,
+    container_name: Option<&'a str>,
+    lease_duration: i8,
+    proposed_lease_id: Option<&'a LeaseId>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> AcquireContainerLeaseBuilder<'a, No, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> AcquireContainerLeaseBuilder<'a, No, No> {
+        AcquireContainerLeaseBuilder {
+            client,
+            p_container_name: PhantomData {},
+            container_name: None,
+            p_lease_duration: PhantomData {},
+            lease_duration: -1,
+            proposed_lease_id: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, LeaseDurationSet> ClientRequired<'a> for AcquireContainerLeaseBuilder<'a, ContainerNameSet, LeaseDurationSet>
+where
+    ContainerNameSet: ToAssign,
+    LeaseDurationSet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a, LeaseDurationSet> ContainerNameRequired<'a> for AcquireContainerLeaseBuilder<'a, Yes, LeaseDurationSet>
+where
+    LeaseDurationSet: ToAssign,
+{
+    #[inline]
+    fn container_name(&self) -> &'a str {
+        self.container_name.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet> LeaseDurationRequired for AcquireContainerLeaseBuilder<'a, ContainerNameSet, Yes>
+where
+    ContainerNameSet: ToAssign,
+{
+    #[inline]
+    fn lease_duration(&self) -> i8 {
+        self.lease_duration
+    }
+}
+
+impl<'a, ContainerNameSet, LeaseDurationSet> ProposedLeaseIdOption<'a> for AcquireContainerLeaseBuilder<'a, ContainerNameSet, LeaseDurationSet>
+where
+    ContainerNameSet: ToAssign,
+    LeaseDurationSet: ToAssign,
+{
+    #[inline]
+    fn proposed_lease_id(&self) -> Option<&'a LeaseId> {
+        self.proposed_lease_id
+    }
+}
+
+impl<'a, ContainerNameSet, LeaseDurationSet> TimeoutOption for AcquireContainerLeaseBuilder<'a, ContainerNameSet, LeaseDurationSet>
+where
+    ContainerNameSet: ToAssign,
+    LeaseDurationSet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, ContainerNameSet, LeaseDurationSet> ClientRequestIdOption<'a> for AcquireContainerLeaseBuilder<'a, ContainerNameSet, LeaseDurationSet>
+where
+    ContainerNameSet: ToAssign,
+    LeaseDurationSet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, ContainerNameSet, LeaseDurationSet> ContainerNameSupport<'a> for AcquireContainerLeaseBuilder<'a, ContainerNameSet, LeaseDurationSet>
+where
+    ContainerNameSet: ToAssign,
+    LeaseDurationSet: ToAssign,
+{
+    type O = AcquireContainerLeaseBuilder<'a, Yes, LeaseDurationSet>;
+
+    #[inline]
+    fn with_container_name(self, container_name: &'a str) -> Self::O {
+        AcquireContainerLeaseBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_lease_duration: PhantomData {},
+            container_name: Some(container_name),
+            lease_duration: self.lease_duration,
+            proposed_lease_id: self.proposed_lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, LeaseDurationSet> LeaseDurationSupport for AcquireContainerLeaseBuilder<'a, ContainerNameSet, LeaseDurationSet>
+where
+    ContainerNameSet: ToAssign,
+    LeaseDurationSet: ToAssign,
+{
+    type O = AcquireContainerLeaseBuilder<'a, ContainerNameSet, Yes>;
+
+    /// Lease duration in seconds, between 15 and 60, or -1 for an infinite lease.
+    #[inline]
+    fn with_lease_duration(self, lease_duration: i8) -> Self::O {
+        AcquireContainerLeaseBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_lease_duration: PhantomData {},
+            container_name: self.container_name,
+            lease_duration,
+            proposed_lease_id: self.proposed_lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, LeaseDurationSet> ProposedLeaseIdSupport<'a> for AcquireContainerLeaseBuilder<'a, ContainerNameSet, LeaseDurationSet>
+where
+    ContainerNameSet: ToAssign,
+    LeaseDurationSet: ToAssign,
+{
+    type O = AcquireContainerLeaseBuilder<'a, ContainerNameSet, LeaseDurationSet>;
+
+    #[inline]
+    fn with_proposed_lease_id(self, proposed_lease_id: &'a LeaseId) -> Self::O {
+        AcquireContainerLeaseBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_lease_duration: PhantomData {},
+            container_name: self.container_name,
+            lease_duration: self.lease_duration,
+            proposed_lease_id: Some(proposed_lease_id),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, LeaseDurationSet> TimeoutSupport for AcquireContainerLeaseBuilder<'a, ContainerNameSet, LeaseDurationSet>
+where
+    ContainerNameSet: ToAssign,
+    LeaseDurationSet: ToAssign,
+{
+    type O = AcquireContainerLeaseBuilder<'a, ContainerNameSet, LeaseDurationSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        AcquireContainerLeaseBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_lease_duration: PhantomData {},
+            container_name: self.container_name,
+            lease_duration: self.lease_duration,
+            proposed_lease_id: self.proposed_lease_id,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, LeaseDurationSet> ClientRequestIdSupport<'a> for AcquireContainerLeaseBuilder<'a, ContainerNameSet, LeaseDurationSet>
+where
+    ContainerNameSet: ToAssign,
+    LeaseDurationSet: ToAssign,
+{
+    type O = AcquireContainerLeaseBuilder<'a, ContainerNameSet, LeaseDurationSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        AcquireContainerLeaseBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_lease_duration: PhantomData {},
+            container_name: self.container_name,
+            lease_duration: self.lease_duration,
+            proposed_lease_id: self.proposed_lease_id,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, ContainerNameSet, LeaseDurationSet> AcquireContainerLeaseBuilder<'a, ContainerNameSet, LeaseDurationSet>
+where
+    ContainerNameSet: ToAssign,
+    LeaseDurationSet: ToAssign,
+{}
+
+impl<'a> AcquireContainerLeaseBuilder<'a, Yes, Yes> {
+    pub fn finalize(self) -> impl Future<Item = AcquireContainerLeaseResponse, Error = AzureError> {
+        let mut uri = generate_container_uri(&self, Some("comp=lease&restype=container"));
+
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+
+        let req = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            |ref mut request| {
+                LeaseDurationRequired::add_header(&self, request);
+                ProposedLeaseIdOption::add_header(&self, request);
+                request.header(LEASE_ACTION, "acquire");
+                ClientRequestIdOption::add_header(&self, request);
+            },
+            None,
+        );
+
+        done(req)
+            .from_err()
+            .and_then(move |future_response| check_status_extract_headers_and_body(future_response, StatusCode::CREATED))
+            .and_then(|(headers, _body)| done(AcquireContainerLeaseResponse::from_headers(&headers)))
+    }
+}
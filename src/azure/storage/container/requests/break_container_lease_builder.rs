@@ -0,0 +1,196 @@
+use azure::core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure::core::headers::LEASE_ACTION;
+use azure::core::{
+    ClientRequestIdOption, ClientRequestIdSupport, ClientRequired, ContainerNameRequired, ContainerNameSupport, LeaseBreakPeriodOption,
+    LeaseBreakPeriodSupport, TimeoutOption, TimeoutSupport,
+};
+use azure::core::{No, ToAssign, Yes};
+use azure::storage::container::generate_container_uri;
+use azure::storage::container::responses::BreakContainerLeaseResponse;
+use azure::storage::client::Client;
+use futures::future::{done, Future};
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct BreakContainerLeaseBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    client: &'a Client,
+    p_container_name: PhantomData<ContainerNameSet>,
+    container_name: Option<&'a str>,
+    lease_break_period: Option<u64>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> BreakContainerLeaseBuilder<'a, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> BreakContainerLeaseBuilder<'a, No> {
+        BreakContainerLeaseBuilder {
+            client,
+            p_container_name: PhantomData {},
+            container_name: None,
+            lease_break_period: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet> ClientRequired<'a> for BreakContainerLeaseBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a> ContainerNameRequired<'a> for BreakContainerLeaseBuilder<'a, Yes> {
+    #[inline]
+    fn container_name(&self) -> &'a str {
+        self.container_name.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet> LeaseBreakPeriodOption for BreakContainerLeaseBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    #[inline]
+    fn lease_break_period(&self) -> Option<u64> {
+        self.lease_break_period
+    }
+}
+
+impl<'a, ContainerNameSet> TimeoutOption for BreakContainerLeaseBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, ContainerNameSet> ClientRequestIdOption<'a> for BreakContainerLeaseBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, ContainerNameSet> ContainerNameSupport<'a> for BreakContainerLeaseBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    type O = BreakContainerLeaseBuilder<'a, Yes>;
+
+    #[inline]
+    fn with_container_name(self, container_name: &'a str) -> Self::O {
+        BreakContainerLeaseBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            container_name: Some(container_name),
+            lease_break_period: self.lease_break_period,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet> LeaseBreakPeriodSupport for BreakContainerLeaseBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    type O = BreakContainerLeaseBuilder<'a, ContainerNameSet>;
+
+    /// Proposed duration, in seconds, that the lease should continue before it is broken.
+    #[inline]
+    fn with_lease_break_period(self, lease_break_period: u64) -> Self::O {
+        BreakContainerLeaseBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            container_name: self.container_name,
+            lease_break_period: Some(lease_break_period),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet> TimeoutSupport for BreakContainerLeaseBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    type O = BreakContainerLeaseBuilder<'a, ContainerNameSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        BreakContainerLeaseBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            container_name: self.container_name,
+            lease_break_period: self.lease_break_period,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet> ClientRequestIdSupport<'a> for BreakContainerLeaseBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    type O = BreakContainerLeaseBuilder<'a, ContainerNameSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        BreakContainerLeaseBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            container_name: self.container_name,
+            lease_break_period: self.lease_break_period,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, ContainerNameSet> BreakContainerLeaseBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{}
+
+impl<'a> BreakContainerLeaseBuilder<'a, Yes> {
+    pub fn finalize(self) -> impl Future<Item = BreakContainerLeaseResponse, Error = AzureError> {
+        let mut uri = generate_container_uri(&self, Some("comp=lease&restype=container"));
+
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+
+        let req = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            |ref mut request| {
+                LeaseBreakPeriodOption::add_header(&self, request);
+                request.header(LEASE_ACTION, "break");
+                ClientRequestIdOption::add_header(&self, request);
+            },
+            None,
+        );
+
+        done(req)
+            .from_err()
+            .and_then(move |future_response| check_status_extract_headers_and_body(future_response, StatusCode::ACCEPTED))
+            .and_then(|(headers, _body)| done(BreakContainerLeaseResponse::from_headers(&headers)))
+    }
+}
@@ -0,0 +1,282 @@
+use azure::core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure::core::headers::LEASE_ACTION;
+use azure::core::lease::LeaseId;
+use azure::core::{
+    ClientRequestIdOption, ClientRequestIdSupport, ClientRequired, ContainerNameRequired, ContainerNameSupport, LeaseIdRequired,
+    LeaseIdSupport, ProposedLeaseIdRequired, ProposedLeaseIdSupport, TimeoutOption, TimeoutSupport,
+};
+use azure::core::{No, ToAssign, Yes};
+use azure::storage::container::generate_container_uri;
+use azure::storage::container::responses::ChangeContainerLeaseResponse;
+use azure::storage::client::Client;
+use futures::future::{done, Future};
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct ChangeContainerLeaseBuilder<'a, ContainerNameSet, LeaseIdSet, ProposedLeaseIdSet>
+where
+    ContainerNameSet: ToAssign,
+    LeaseIdSet: ToAssign,
+    ProposedLeaseIdSet: ToAssign,
+{
+    client: &'a Client,
+    p_container_name: PhantomData<ContainerNameSet>,
+    p_lease_id: PhantomData<LeaseIdSet>,
+    p_proposed_lease_id: PhantomData<ProposedLeaseIdSet>,
+    container_name: Option<&'a str>,
+    lease_id: Option<&'a LeaseId>,
+    proposed_lease_id: Option<&'a LeaseId>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> ChangeContainerLeaseBuilder<'a, No, No, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> ChangeContainerLeaseBuilder<'a, No, No, No> {
+        ChangeContainerLeaseBuilder {
+            client,
+            p_container_name: PhantomData {},
+            container_name: None,
+            p_lease_id: PhantomData {},
+            lease_id: None,
+            p_proposed_lease_id: PhantomData {},
+            proposed_lease_id: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, LeaseIdSet, ProposedLeaseIdSet> ClientRequired<'a>
+    for ChangeContainerLeaseBuilder<'a, ContainerNameSet, LeaseIdSet, ProposedLeaseIdSet>
+where
+    ContainerNameSet: ToAssign,
+    LeaseIdSet: ToAssign,
+    ProposedLeaseIdSet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a, LeaseIdSet, ProposedLeaseIdSet> ContainerNameRequired<'a> for ChangeContainerLeaseBuilder<'a, Yes, LeaseIdSet, ProposedLeaseIdSet>
+where
+    LeaseIdSet: ToAssign,
+    ProposedLeaseIdSet: ToAssign,
+{
+    #[inline]
+    fn container_name(&self) -> &'a str {
+        self.container_name.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet, ProposedLeaseIdSet> LeaseIdRequired<'a>
+    for ChangeContainerLeaseBuilder<'a, ContainerNameSet, Yes, ProposedLeaseIdSet>
+where
+    ContainerNameSet: ToAssign,
+    ProposedLeaseIdSet: ToAssign,
+{
+    #[inline]
+    fn lease_id(&self) -> &'a LeaseId {
+        self.lease_id.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet, LeaseIdSet> ProposedLeaseIdRequired<'a> for ChangeContainerLeaseBuilder<'a, ContainerNameSet, LeaseIdSet, Yes>
+where
+    ContainerNameSet: ToAssign,
+    LeaseIdSet: ToAssign,
+{
+    #[inline]
+    fn proposed_lease_id(&self) -> &'a LeaseId {
+        self.proposed_lease_id.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet, LeaseIdSet, ProposedLeaseIdSet> TimeoutOption
+    for ChangeContainerLeaseBuilder<'a, ContainerNameSet, LeaseIdSet, ProposedLeaseIdSet>
+where
+    ContainerNameSet: ToAssign,
+    LeaseIdSet: ToAssign,
+    ProposedLeaseIdSet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, ContainerNameSet, LeaseIdSet, ProposedLeaseIdSet> ClientRequestIdOption<'a>
+    for ChangeContainerLeaseBuilder<'a, ContainerNameSet, LeaseIdSet, ProposedLeaseIdSet>
+where
+    ContainerNameSet: ToAssign,
+    LeaseIdSet: ToAssign,
+    ProposedLeaseIdSet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, ContainerNameSet, LeaseIdSet, ProposedLeaseIdSet> ContainerNameSupport<'a>
+    for ChangeContainerLeaseBuilder<'a, ContainerNameSet, LeaseIdSet, ProposedLeaseIdSet>
+where
+    ContainerNameSet: ToAssign,
+    LeaseIdSet: ToAssign,
+    ProposedLeaseIdSet: ToAssign,
+{
+    type O = ChangeContainerLeaseBuilder<'a, Yes, LeaseIdSet, ProposedLeaseIdSet>;
+
+    #[inline]
+    fn with_container_name(self, container_name: &'a str) -> Self::O {
+        ChangeContainerLeaseBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_lease_id: PhantomData {},
+            p_proposed_lease_id: PhantomData {},
+            container_name: Some(container_name),
+            lease_id: self.lease_id,
+            proposed_lease_id: self.proposed_lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, LeaseIdSet, ProposedLeaseIdSet> LeaseIdSupport<'a>
+    for ChangeContainerLeaseBuilder<'a, ContainerNameSet, LeaseIdSet, ProposedLeaseIdSet>
+where
+    ContainerNameSet: ToAssign,
+    LeaseIdSet: ToAssign,
+    ProposedLeaseIdSet: ToAssign,
+{
+    type O = ChangeContainerLeaseBuilder<'a, ContainerNameSet, Yes, ProposedLeaseIdSet>;
+
+    #[inline]
+    fn with_lease_id(self, lease_id: &'a LeaseId) -> Self::O {
+        ChangeContainerLeaseBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_lease_id: PhantomData {},
+            p_proposed_lease_id: PhantomData {},
+            container_name: self.container_name,
+            lease_id: Some(lease_id),
+            proposed_lease_id: self.proposed_lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, LeaseIdSet, ProposedLeaseIdSet> ProposedLeaseIdSupport<'a>
+    for ChangeContainerLeaseBuilder<'a, ContainerNameSet, LeaseIdSet, ProposedLeaseIdSet>
+where
+    ContainerNameSet: ToAssign,
+    LeaseIdSet: ToAssign,
+    ProposedLeaseIdSet: ToAssign,
+{
+    type O = ChangeContainerLeaseBuilder<'a, ContainerNameSet, LeaseIdSet, Yes>;
+
+    #[inline]
+    fn with_proposed_lease_id(self, proposed_lease_id: &'a LeaseId) -> Self::O {
+        ChangeContainerLeaseBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_lease_id: PhantomData {},
+            p_proposed_lease_id: PhantomData {},
+            container_name: self.container_name,
+            lease_id: self.lease_id,
+            proposed_lease_id: Some(proposed_lease_id),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, LeaseIdSet, ProposedLeaseIdSet> TimeoutSupport
+    for ChangeContainerLeaseBuilder<'a, ContainerNameSet, LeaseIdSet, ProposedLeaseIdSet>
+where
+    ContainerNameSet: ToAssign,
+    LeaseIdSet: ToAssign,
+    ProposedLeaseIdSet: ToAssign,
+{
+    type O = ChangeContainerLeaseBuilder<'a, ContainerNameSet, LeaseIdSet, ProposedLeaseIdSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        ChangeContainerLeaseBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_lease_id: PhantomData {},
+            p_proposed_lease_id: PhantomData {},
+            container_name: self.container_name,
+            lease_id: self.lease_id,
+            proposed_lease_id: self.proposed_lease_id,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, LeaseIdSet, ProposedLeaseIdSet> ClientRequestIdSupport<'a>
+    for ChangeContainerLeaseBuilder<'a, ContainerNameSet, LeaseIdSet, ProposedLeaseIdSet>
+where
+    ContainerNameSet: ToAssign,
+    LeaseIdSet: ToAssign,
+    ProposedLeaseIdSet: ToAssign,
+{
+    type O = ChangeContainerLeaseBuilder<'a, ContainerNameSet, LeaseIdSet, ProposedLeaseIdSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        ChangeContainerLeaseBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_lease_id: PhantomData {},
+            p_proposed_lease_id: PhantomData {},
+            container_name: self.container_name,
+            lease_id: self.lease_id,
+            proposed_lease_id: self.proposed_lease_id,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, ContainerNameSet, LeaseIdSet, ProposedLeaseIdSet> ChangeContainerLeaseBuilder<'a, ContainerNameSet, LeaseIdSet, ProposedLeaseIdSet>
+where
+    ContainerNameSet: ToAssign,
+    LeaseIdSet: ToAssign,
+    ProposedLeaseIdSet: ToAssign,
+{}
+
+impl<'a> ChangeContainerLeaseBuilder<'a, Yes, Yes, Yes> {
+    pub fn finalize(self) -> impl Future<Item = ChangeContainerLeaseResponse, Error = AzureError> {
+        let mut uri = generate_container_uri(&self, Some("comp=lease&restype=container"));
+
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+
+        let req = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            |ref mut request| {
+                LeaseIdRequired::add_header(&self, request);
+                ProposedLeaseIdRequired::add_header(&self, request);
+                request.header(LEASE_ACTION, "change");
+                ClientRequestIdOption::add_header(&self, request);
+            },
+            None,
+        );
+
+        done(req)
+            .from_err()
+            .and_then(move |future_response| check_status_extract_headers_and_body(future_response, StatusCode::OK))
+            .and_then(|(headers, _body)| done(ChangeContainerLeaseResponse::from_headers(&headers)))
+    }
+}
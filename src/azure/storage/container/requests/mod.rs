@@ -0,0 +1,9 @@
+mod acquire_container_lease_builder;
+mod break_container_lease_builder;
+mod change_container_lease_builder;
+mod renew_container_lease_builder;
+
+pub use self::acquire_container_lease_builder::AcquireContainerLeaseBuilder;
+pub use self::break_container_lease_builder::BreakContainerLeaseBuilder;
+pub use self::change_container_lease_builder::ChangeContainerLeaseBuilder;
+pub use self::renew_container_lease_builder::RenewContainerLeaseBuilder;
@@ -0,0 +1,214 @@
+use azure::core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure::core::headers::LEASE_ACTION;
+use azure::core::lease::LeaseId;
+use azure::core::{
+    ClientRequestIdOption, ClientRequestIdSupport, ClientRequired, ContainerNameRequired, ContainerNameSupport, LeaseIdRequired,
+    LeaseIdSupport, TimeoutOption, TimeoutSupport,
+};
+use azure::core::{No, ToAssign, Yes};
+use azure::storage::container::generate_container_uri;
+use azure::storage::container::responses::RenewContainerLeaseResponse;
+use azure::storage::client::Client;
+use futures::future::{done, Future};
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct RenewContainerLeaseBuilder<'a, ContainerNameSet, LeaseIdSet>
+where
+    ContainerNameSet: ToAssign,
+    LeaseIdSet: ToAssign,
+{
+    client: &'a Client,
+    p_container_name: PhantomData<ContainerNameSet>,
+    p_lease_id: PhantomData<LeaseIdSet>,
+    container_name: Option<&'a str>,
+    lease_id: Option<&'a LeaseId>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> RenewContainerLeaseBuilder<'a, No, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> RenewContainerLeaseBuilder<'a, No, No> {
+        RenewContainerLeaseBuilder {
+            client,
+            p_container_name: PhantomData {},
+            container_name: None,
+            p_lease_id: PhantomData {},
+            lease_id: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, LeaseIdSet> ClientRequired<'a> for RenewContainerLeaseBuilder<'a, ContainerNameSet, LeaseIdSet>
+where
+    ContainerNameSet: ToAssign,
+    LeaseIdSet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a, LeaseIdSet> ContainerNameRequired<'a> for RenewContainerLeaseBuilder<'a, Yes, LeaseIdSet>
+where
+    LeaseIdSet: ToAssign,
+{
+    #[inline]
+    fn container_name(&self) -> &'a str {
+        self.container_name.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet> LeaseIdRequired<'a> for RenewContainerLeaseBuilder<'a, ContainerNameSet, Yes>
+where
+    ContainerNameSet: ToAssign,
+{
+    #[inline]
+    fn lease_id(&self) -> &'a LeaseId {
+        self.lease_id.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet, LeaseIdSet> TimeoutOption for RenewContainerLeaseBuilder<'a, ContainerNameSet, LeaseIdSet>
+where
+    ContainerNameSet: ToAssign,
+    LeaseIdSet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, ContainerNameSet, LeaseIdSet> ClientRequestIdOption<'a> for RenewContainerLeaseBuilder<'a, ContainerNameSet, LeaseIdSet>
+where
+    ContainerNameSet: ToAssign,
+    LeaseIdSet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, ContainerNameSet, LeaseIdSet> ContainerNameSupport<'a> for RenewContainerLeaseBuilder<'a, ContainerNameSet, LeaseIdSet>
+where
+    ContainerNameSet: ToAssign,
+    LeaseIdSet: ToAssign,
+{
+    type O = RenewContainerLeaseBuilder<'a, Yes, LeaseIdSet>;
+
+    #[inline]
+    fn with_container_name(self, container_name: &'a str) -> Self::O {
+        RenewContainerLeaseBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_lease_id: PhantomData {},
+            container_name: Some(container_name),
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, LeaseIdSet> LeaseIdSupport<'a> for RenewContainerLeaseBuilder<'a, ContainerNameSet, LeaseIdSet>
+where
+    ContainerNameSet: ToAssign,
+    LeaseIdSet: ToAssign,
+{
+    type O = RenewContainerLeaseBuilder<'a, ContainerNameSet, Yes>;
+
+    #[inline]
+    fn with_lease_id(self, lease_id: &'a LeaseId) -> Self::O {
+        RenewContainerLeaseBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_lease_id: PhantomData {},
+            container_name: self.container_name,
+            lease_id: Some(lease_id),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, LeaseIdSet> TimeoutSupport for RenewContainerLeaseBuilder<'a, ContainerNameSet, LeaseIdSet>
+where
+    ContainerNameSet: ToAssign,
+    LeaseIdSet: ToAssign,
+{
+    type O = RenewContainerLeaseBuilder<'a, ContainerNameSet, LeaseIdSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        RenewContainerLeaseBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_lease_id: PhantomData {},
+            container_name: self.container_name,
+            lease_id: self.lease_id,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, LeaseIdSet> ClientRequestIdSupport<'a> for RenewContainerLeaseBuilder<'a, ContainerNameSet, LeaseIdSet>
+where
+    ContainerNameSet: ToAssign,
+    LeaseIdSet: ToAssign,
+{
+    type O = RenewContainerLeaseBuilder<'a, ContainerNameSet, LeaseIdSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        RenewContainerLeaseBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_lease_id: PhantomData {},
+            container_name: self.container_name,
+            lease_id: self.lease_id,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, ContainerNameSet, LeaseIdSet> RenewContainerLeaseBuilder<'a, ContainerNameSet, LeaseIdSet>
+where
+    ContainerNameSet: ToAssign,
+    LeaseIdSet: ToAssign,
+{}
+
+impl<'a> RenewContainerLeaseBuilder<'a, Yes, Yes> {
+    pub fn finalize(self) -> impl Future<Item = RenewContainerLeaseResponse, Error = AzureError> {
+        let mut uri = generate_container_uri(&self, Some("comp=lease&restype=container"));
+
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+
+        let req = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            |ref mut request| {
+                LeaseIdRequired::add_header(&self, request);
+                request.header(LEASE_ACTION, "renew");
+                ClientRequestIdOption::add_header(&self, request);
+            },
+            None,
+        );
+
+        done(req)
+            .from_err()
+            .and_then(move |future_response| check_status_extract_headers_and_body(future_response, StatusCode::OK))
+            .and_then(|(headers, _body)| done(RenewContainerLeaseResponse::from_headers(&headers)))
+    }
+}
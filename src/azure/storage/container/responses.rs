@@ -0,0 +1,69 @@
+use azure::core::errors::AzureError;
+use azure::core::headers::{LEASE_ID, LEASE_TIME};
+use azure::core::lease::LeaseId;
+use http::HeaderMap;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AcquireContainerLeaseResponse {
+    pub lease_id: LeaseId,
+}
+
+impl AcquireContainerLeaseResponse {
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Result<AcquireContainerLeaseResponse, AzureError> {
+        let lease_id = match headers.get(LEASE_ID) {
+            Some(lease_id) => LeaseId::from_str(lease_id.to_str()?)?,
+            None => return Err(AzureError::MissingHeaderError(LEASE_ID.to_owned())),
+        };
+
+        Ok(AcquireContainerLeaseResponse { lease_id })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RenewContainerLeaseResponse {
+    pub lease_id: LeaseId,
+}
+
+impl RenewContainerLeaseResponse {
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Result<RenewContainerLeaseResponse, AzureError> {
+        let lease_id = match headers.get(LEASE_ID) {
+            Some(lease_id) => LeaseId::from_str(lease_id.to_str()?)?,
+            None => return Err(AzureError::MissingHeaderError(LEASE_ID.to_owned())),
+        };
+
+        Ok(RenewContainerLeaseResponse { lease_id })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChangeContainerLeaseResponse {
+    pub lease_id: LeaseId,
+}
+
+impl ChangeContainerLeaseResponse {
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Result<ChangeContainerLeaseResponse, AzureError> {
+        let lease_id = match headers.get(LEASE_ID) {
+            Some(lease_id) => LeaseId::from_str(lease_id.to_str()?)?,
+            None => return Err(AzureError::MissingHeaderError(LEASE_ID.to_owned())),
+        };
+
+        Ok(ChangeContainerLeaseResponse { lease_id })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BreakContainerLeaseResponse {
+    pub lease_time: u64,
+}
+
+impl BreakContainerLeaseResponse {
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Result<BreakContainerLeaseResponse, AzureError> {
+        let lease_time = match headers.get(LEASE_TIME) {
+            Some(lease_time) => u64::from_str(lease_time.to_str()?)?,
+            None => return Err(AzureError::MissingHeaderError(LEASE_TIME.to_owned())),
+        };
+
+        Ok(BreakContainerLeaseResponse { lease_time })
+    }
+}
@@ -0,0 +1,94 @@
+pub mod requests;
+pub mod responses;
+
+use azure::core::{
+    errors::AzureError,
+    parsing::{cast_must, traverse},
+    ClientRequired,
+};
+use xml::{Element, Xml};
+
+/// A single CORS rule of a Blob service's `StorageServiceProperties`, matching the shape
+/// documented at
+/// https://docs.microsoft.com/en-us/rest/api/storageservices/set-blob-service-properties.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CorsRule {
+    pub allowed_origins: Vec<String>,
+    pub allowed_methods: Vec<String>,
+    pub allowed_headers: Vec<String>,
+    pub exposed_headers: Vec<String>,
+    pub max_age_in_seconds: u32,
+}
+
+impl CorsRule {
+    fn parse(elem: &Element) -> Result<CorsRule, AzureError> {
+        let allowed_origins = cast_must::<String>(elem, &["AllowedOrigins"])?;
+        let allowed_methods = cast_must::<String>(elem, &["AllowedMethods"])?;
+        let allowed_headers = cast_must::<String>(elem, &["AllowedHeaders"])?;
+        let exposed_headers = cast_must::<String>(elem, &["ExposedHeaders"])?;
+        let max_age_in_seconds = cast_must::<u32>(elem, &["MaxAgeInSeconds"])?;
+
+        Ok(CorsRule {
+            allowed_origins: split_comma_list(&allowed_origins),
+            allowed_methods: split_comma_list(&allowed_methods),
+            allowed_headers: split_comma_list(&allowed_headers),
+            exposed_headers: split_comma_list(&exposed_headers),
+            max_age_in_seconds,
+        })
+    }
+
+    fn to_xml(&self) -> String {
+        format!(
+            "<CorsRule><AllowedOrigins>{}</AllowedOrigins><AllowedMethods>{}</AllowedMethods><AllowedHeaders>{}</AllowedHeaders><ExposedHeaders>{}</ExposedHeaders><MaxAgeInSeconds>{}</MaxAgeInSeconds></CorsRule>",
+            self.allowed_origins.join(","),
+            self.allowed_methods.join(","),
+            self.allowed_headers.join(","),
+            self.exposed_headers.join(","),
+            self.max_age_in_seconds,
+        )
+    }
+}
+
+fn split_comma_list(list: &str) -> Vec<String> {
+    list.split(',').map(str::trim).filter(|s| !s.is_empty()).map(str::to_owned).collect()
+}
+
+/// Account-level Blob service properties (`StorageServiceProperties`). Only CORS is modeled today;
+/// logging and metrics can be added the same way once there is a need for them.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct StorageServiceProperties {
+    pub cors: Vec<CorsRule>,
+}
+
+impl StorageServiceProperties {
+    pub(crate) fn parse(elem: &Element) -> Result<StorageServiceProperties, AzureError> {
+        let mut cors = Vec::new();
+
+        for cors_elem in traverse(elem, &["Cors"], true)? {
+            for child in &cors_elem.children {
+                if let Xml::ElementNode(rule_elem) = child {
+                    cors.push(CorsRule::parse(rule_elem)?);
+                }
+            }
+        }
+
+        Ok(StorageServiceProperties { cors })
+    }
+
+    pub(crate) fn to_xml(&self) -> String {
+        let cors_rules: String = self.cors.iter().map(CorsRule::to_xml).collect();
+
+        format!(
+            "<?xml version=\"1.0\" encoding=\"utf-8\"?><StorageServiceProperties><Cors>{}</Cors></StorageServiceProperties>",
+            cors_rules
+        )
+    }
+}
+
+#[inline]
+pub(crate) fn generate_service_uri<'a, T>(t: &T, params: &str) -> String
+where
+    T: ClientRequired<'a>,
+{
+    format!("https://{}.blob.core.windows.net/?{}", t.client().account(), params)
+}
@@ -0,0 +1,91 @@
+use azure::core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure::core::{ClientRequestIdOption, ClientRequestIdSupport, ClientRequired, TimeoutOption, TimeoutSupport};
+use azure::storage::client::Client;
+use azure::storage::service::generate_service_uri;
+use azure::storage::service::responses::GetBlobServicePropertiesResponse;
+use futures::future::{done, Future};
+use hyper::{Method, StatusCode};
+
+#[derive(Debug, Clone)]
+pub struct GetBlobServicePropertiesBuilder<'a> {
+    client: &'a Client,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> GetBlobServicePropertiesBuilder<'a> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> GetBlobServicePropertiesBuilder<'a> {
+        GetBlobServicePropertiesBuilder {
+            client,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a> ClientRequired<'a> for GetBlobServicePropertiesBuilder<'a> {
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a> TimeoutOption for GetBlobServicePropertiesBuilder<'a> {
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a> ClientRequestIdOption<'a> for GetBlobServicePropertiesBuilder<'a> {
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a> TimeoutSupport for GetBlobServicePropertiesBuilder<'a> {
+    type O = GetBlobServicePropertiesBuilder<'a>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        GetBlobServicePropertiesBuilder { timeout: Some(timeout), ..self }
+    }
+}
+
+impl<'a> ClientRequestIdSupport<'a> for GetBlobServicePropertiesBuilder<'a> {
+    type O = GetBlobServicePropertiesBuilder<'a>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        GetBlobServicePropertiesBuilder {
+            client_request_id: Some(client_request_id),
+            ..self
+        }
+    }
+}
+
+impl<'a> GetBlobServicePropertiesBuilder<'a> {
+    pub fn finalize(self) -> impl Future<Item = GetBlobServicePropertiesResponse, Error = AzureError> {
+        let mut uri = generate_service_uri(&self, "restype=service&comp=properties");
+
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+
+        let req = self.client().perform_request(
+            &uri,
+            &Method::GET,
+            |ref mut request| {
+                ClientRequestIdOption::add_header(&self, request);
+            },
+            None,
+        );
+
+        done(req)
+            .from_err()
+            .and_then(move |future_response| check_status_extract_headers_and_body(future_response, StatusCode::OK))
+            .and_then(|(_headers, body)| done(GetBlobServicePropertiesResponse::from_body(&body)))
+    }
+}
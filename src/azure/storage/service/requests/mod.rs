@@ -0,0 +1,5 @@
+mod get_blob_service_properties_builder;
+mod set_blob_service_properties_builder;
+
+pub use self::get_blob_service_properties_builder::GetBlobServicePropertiesBuilder;
+pub use self::set_blob_service_properties_builder::SetBlobServicePropertiesBuilder;
@@ -0,0 +1,136 @@
+use azure::core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure::core::{ClientRequestIdOption, ClientRequestIdSupport, ClientRequired, TimeoutOption, TimeoutSupport};
+use azure::core::{No, ToAssign, Yes};
+use azure::storage::client::Client;
+use azure::storage::service::generate_service_uri;
+use azure::storage::service::responses::SetBlobServicePropertiesResponse;
+use azure::storage::service::StorageServiceProperties;
+use futures::future::{done, Future};
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct SetBlobServicePropertiesBuilder<'a, PropertiesSet>
+where
+    PropertiesSet: ToAssign,
+{
+    client: &'a Client,
+    p_properties: PhantomData<PropertiesSet>,
+    properties: Option<&'a StorageServiceProperties>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> SetBlobServicePropertiesBuilder<'a, No> {
+    #[inline]
+    pub(crate) fn new(client: &'a Client) -> SetBlobServicePropertiesBuilder<'a, No> {
+        SetBlobServicePropertiesBuilder {
+            client,
+            p_properties: PhantomData {},
+            properties: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, PropertiesSet> ClientRequired<'a> for SetBlobServicePropertiesBuilder<'a, PropertiesSet>
+where
+    PropertiesSet: ToAssign,
+{
+    #[inline]
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a, PropertiesSet> TimeoutOption for SetBlobServicePropertiesBuilder<'a, PropertiesSet>
+where
+    PropertiesSet: ToAssign,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, PropertiesSet> ClientRequestIdOption<'a> for SetBlobServicePropertiesBuilder<'a, PropertiesSet>
+where
+    PropertiesSet: ToAssign,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, PropertiesSet> TimeoutSupport for SetBlobServicePropertiesBuilder<'a, PropertiesSet>
+where
+    PropertiesSet: ToAssign,
+{
+    type O = SetBlobServicePropertiesBuilder<'a, PropertiesSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        SetBlobServicePropertiesBuilder {
+            timeout: Some(timeout),
+            ..self
+        }
+    }
+}
+
+impl<'a, PropertiesSet> ClientRequestIdSupport<'a> for SetBlobServicePropertiesBuilder<'a, PropertiesSet>
+where
+    PropertiesSet: ToAssign,
+{
+    type O = SetBlobServicePropertiesBuilder<'a, PropertiesSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        SetBlobServicePropertiesBuilder {
+            client_request_id: Some(client_request_id),
+            ..self
+        }
+    }
+}
+
+impl<'a, PropertiesSet> SetBlobServicePropertiesBuilder<'a, PropertiesSet>
+where
+    PropertiesSet: ToAssign,
+{
+    pub fn with_properties(self, properties: &'a StorageServiceProperties) -> SetBlobServicePropertiesBuilder<'a, Yes> {
+        SetBlobServicePropertiesBuilder {
+            client: self.client,
+            p_properties: PhantomData {},
+            properties: Some(properties),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a> SetBlobServicePropertiesBuilder<'a, Yes> {
+    pub fn finalize(self) -> impl Future<Item = SetBlobServicePropertiesResponse, Error = AzureError> {
+        let mut uri = generate_service_uri(&self, "restype=service&comp=properties");
+
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+
+        let body = self.properties.unwrap().to_xml();
+
+        let req = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            |ref mut request| {
+                ClientRequestIdOption::add_header(&self, request);
+            },
+            Some(body.as_bytes()),
+        );
+
+        done(req)
+            .from_err()
+            .and_then(move |future_response| check_status_extract_headers_and_body(future_response, StatusCode::ACCEPTED))
+            .and_then(|(_headers, _body)| done(SetBlobServicePropertiesResponse::from_headers()))
+    }
+}
@@ -0,0 +1,31 @@
+use azure::core::errors::AzureError;
+use azure::storage::service::StorageServiceProperties;
+use xml::Element;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GetBlobServicePropertiesResponse {
+    pub properties: StorageServiceProperties,
+}
+
+impl GetBlobServicePropertiesResponse {
+    pub(crate) fn from_body(body: &[u8]) -> Result<GetBlobServicePropertiesResponse, AzureError> {
+        let body = String::from_utf8(body.to_vec())
+            .map_err(|e| AzureError::UnexpectedXMLError(format!("StorageServiceProperties body is not valid UTF-8: {:?}", e)))?;
+        let elem: Element = body
+            .parse()
+            .map_err(|e| AzureError::UnexpectedXMLError(format!("could not parse StorageServiceProperties: {:?}", e)))?;
+
+        Ok(GetBlobServicePropertiesResponse {
+            properties: StorageServiceProperties::parse(&elem)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SetBlobServicePropertiesResponse {}
+
+impl SetBlobServicePropertiesResponse {
+    pub(crate) fn from_headers() -> Result<SetBlobServicePropertiesResponse, AzureError> {
+        Ok(SetBlobServicePropertiesResponse {})
+    }
+}